@@ -1,13 +1,91 @@
+use super::bins::Bins;
+use super::edges::Edges;
 use super::errors::BinNotFound;
 use super::grid::Grid;
 use ndarray::prelude::{ArrayBase, ArrayD, ArrayViewD, Axis, Ix1, Ix2};
-use ndarray::Data;
+use ndarray::{Data, Zip};
+use num_traits::{Float, ToPrimitive};
 use std::ops::Add;
 
+/// The reduction applied to the values falling into each bin of a
+/// [`BinnedStatistic`], mirroring the `statistic` parameter of SciPy's
+/// `binned_statistic_dd`.
+///
+/// [`BinnedStatistic`]: struct.BinnedStatistic.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Statistic {
+    /// Number of samples falling in each bin.
+    Count,
+    /// Sum of the values falling in each bin.
+    Sum,
+    /// Mean of the values falling in each bin.
+    Mean,
+    /// Standard deviation of the values falling in each bin.
+    Std,
+    /// Minimum of the values falling in each bin.
+    Min,
+    /// Maximum of the values falling in each bin.
+    Max,
+    /// Median of the values falling in each bin.
+    Median,
+}
+
+/// Error returned when attempting to merge two incompatible [`BinnedStatistic`]s.
+///
+/// [`BinnedStatistic`]: struct.BinnedStatistic.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MergeError {
+    /// The two [`BinnedStatistic`]s were not built from the same [`Grid`].
+    ///
+    /// [`BinnedStatistic`]: struct.BinnedStatistic.html
+    /// [`Grid`]: struct.Grid.html
+    GridMismatch,
+    /// The two [`BinnedStatistic`]s were not built with the same [`Statistic`].
+    ///
+    /// Merging them would silently drop whichever side's `min`/`max`/`values`
+    /// were never populated, since [`add_sample`] only tracks the extrema and
+    /// sample list relevant to `self.statistic`.
+    ///
+    /// [`BinnedStatistic`]: struct.BinnedStatistic.html
+    /// [`Statistic`]: enum.Statistic.html
+    /// [`add_sample`]: struct.BinnedStatistic.html#method.add_sample
+    StatisticMismatch,
+}
+
+impl std::fmt::Display for MergeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MergeError::GridMismatch => write!(
+                f,
+                "`BinnedStatistic`s can only be merged if they share the same `Grid`"
+            ),
+            MergeError::StatisticMismatch => write!(
+                f,
+                "`BinnedStatistic`s can only be merged if they share the same `Statistic`"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MergeError {}
+
 /// Binned statistic data structure.
 pub struct BinnedStatistic<A: Ord, T: num_traits::Num> {
     counts: ArrayD<usize>,
     sum: ArrayD<T>,
+    /// Running mean per bin (Welford's online algorithm), used to derive `variance`/`std`.
+    mean_acc: ArrayD<T>,
+    /// Running sum of squared deviations from `mean_acc` per bin (Welford's `M2`).
+    s_stat: ArrayD<T>,
+    min: ArrayD<Option<T>>,
+    max: ArrayD<Option<T>>,
+    values: ArrayD<Vec<T>>,
+    /// Running sum of weights per bin (`Σw`). `add_sample` contributes an
+    /// implicit weight of 1; `add_weighted_sample` contributes the given weight.
+    weight_sum: ArrayD<T>,
+    /// Running sum of squared weights per bin (`Σw²`), companion to `weight_sum`.
+    weight_sq_sum: ArrayD<T>,
+    statistic: Statistic,
     grid: Grid<A>,
 }
 
@@ -16,13 +94,50 @@ where
     A: Ord,
     T: Clone + num_traits::Num,
 {
-    /// Returns a new instance of BinnedStatistic given a [`Grid`].
+    /// Returns a new instance of BinnedStatistic given a [`Grid`], accumulating
+    /// the `count` and `sum` statistics (see [`Statistic`]).
     ///
     /// [`Grid`]: struct.Grid.html
+    /// [`Statistic`]: enum.Statistic.html
     pub fn new(grid: Grid<A>) -> Self {
+        Self::with_statistic(grid, Statistic::Sum)
+    }
+
+    /// Returns a new instance of `BinnedStatistic` given a [`Grid`] and the
+    /// [`Statistic`] that [`result`] should reduce each bin to.
+    ///
+    /// `counts` and `sum` are always accumulated, regardless of `statistic`,
+    /// so [`counts`] and [`sum`] remain available no matter which statistic
+    /// was requested.
+    ///
+    /// [`Grid`]: struct.Grid.html
+    /// [`Statistic`]: enum.Statistic.html
+    /// [`result`]: #method.result
+    /// [`counts`]: #method.counts
+    /// [`sum`]: #method.sum
+    pub fn with_statistic(grid: Grid<A>, statistic: Statistic) -> Self {
         let counts = ArrayD::zeros(grid.shape());
         let sum = ArrayD::zeros(grid.shape());
-        BinnedStatistic { counts, sum, grid }
+        let mean_acc = ArrayD::zeros(grid.shape());
+        let s_stat = ArrayD::zeros(grid.shape());
+        let min = ArrayD::from_elem(grid.shape(), None);
+        let max = ArrayD::from_elem(grid.shape(), None);
+        let values = ArrayD::from_elem(grid.shape(), Vec::new());
+        let weight_sum = ArrayD::zeros(grid.shape());
+        let weight_sq_sum = ArrayD::zeros(grid.shape());
+        BinnedStatistic {
+            counts,
+            sum,
+            mean_acc,
+            s_stat,
+            min,
+            max,
+            values,
+            weight_sum,
+            weight_sq_sum,
+            statistic,
+            grid,
+        }
     }
 
     /// Adds a single sample to the binned statistic.
@@ -56,18 +171,121 @@ where
     pub fn add_sample<S>(&mut self, sample: &ArrayBase<S, Ix1>, value: T) -> Result<(), BinNotFound>
     where
         S: Data<Elem = A>,
-        T: Copy + num_traits::Num,
+        T: Copy + num_traits::Num + PartialOrd,
     {
         match self.grid.index_of(sample) {
             Some(bin_index) => {
-                self.counts[&*bin_index] += 1usize;
-                self.sum[&*bin_index] = self.sum[&*bin_index] + value;
+                let bin_index = &*bin_index;
+                self.counts[bin_index] += 1usize;
+                self.sum[bin_index] = self.sum[bin_index] + value;
+                // An unweighted sample carries an implicit weight of 1.
+                self.accumulate_weighted(bin_index, value, T::one());
+                match self.statistic {
+                    Statistic::Min => {
+                        let is_smaller = self.min[bin_index].map_or(true, |min| value < min);
+                        if is_smaller {
+                            self.min[bin_index] = Some(value);
+                        }
+                    }
+                    Statistic::Max => {
+                        let is_larger = self.max[bin_index].map_or(true, |max| value > max);
+                        if is_larger {
+                            self.max[bin_index] = Some(value);
+                        }
+                    }
+                    Statistic::Median => self.values[bin_index].push(value),
+                    _ => {}
+                }
                 Ok(())
             }
             None => Err(BinNotFound),
         }
     }
 
+    /// Adds a single importance-weighted sample to the binned statistic, as
+    /// driven by [`BinnedStatisticExt::binned_statistic_weighted`].
+    ///
+    /// Unlike [`add_sample`], `counts` is still incremented by `1` (the raw
+    /// number of observations), while `sum` accumulates `weight * value` and
+    /// [`weight_sum`]/[`weight_sq_sum`] accumulate `weight` and `weight²`
+    /// respectively, so that callers can recover the effective sample size
+    /// (see [`effective_count`]) for error propagation on importance-sampled
+    /// data (e.g. Monte-Carlo output carrying an acceptance weight).
+    ///
+    /// Feeds the same weighted-Welford accumulators backing [`variance`]/
+    /// [`std`] as [`add_sample`] does (which is the special case `weight ==
+    /// 1`), so `variance`/`std`/`standard_error` remain meaningful on bins
+    /// touched by `add_weighted_sample`. Still tracks `min`/`max`/the sample
+    /// list backing [`Statistic::Median`], on the unweighted `value`, exactly
+    /// as [`add_sample`] does.
+    ///
+    /// [`add_sample`]: #method.add_sample
+    /// [`BinnedStatisticExt::binned_statistic_weighted`]: trait.BinnedStatisticExt.html#tymethod.binned_statistic_weighted
+    /// [`weight_sum`]: #method.weight_sum
+    /// [`weight_sq_sum`]: #method.weight_sq_sum
+    /// [`effective_count`]: #method.effective_count
+    /// [`variance`]: #method.variance
+    /// [`std`]: #method.std
+    /// [`Statistic::Median`]: enum.Statistic.html#variant.Median
+    pub fn add_weighted_sample<S>(
+        &mut self,
+        sample: &ArrayBase<S, Ix1>,
+        value: T,
+        weight: T,
+    ) -> Result<(), BinNotFound>
+    where
+        S: Data<Elem = A>,
+        T: Copy + num_traits::Num + PartialOrd,
+    {
+        match self.grid.index_of(sample) {
+            Some(bin_index) => {
+                let bin_index = &*bin_index;
+                self.counts[bin_index] += 1usize;
+                self.sum[bin_index] = self.sum[bin_index] + weight * value;
+                self.accumulate_weighted(bin_index, value, weight);
+                match self.statistic {
+                    Statistic::Min => {
+                        let is_smaller = self.min[bin_index].map_or(true, |min| value < min);
+                        if is_smaller {
+                            self.min[bin_index] = Some(value);
+                        }
+                    }
+                    Statistic::Max => {
+                        let is_larger = self.max[bin_index].map_or(true, |max| value > max);
+                        if is_larger {
+                            self.max[bin_index] = Some(value);
+                        }
+                    }
+                    Statistic::Median => self.values[bin_index].push(value),
+                    _ => {}
+                }
+                Ok(())
+            }
+            None => Err(BinNotFound),
+        }
+    }
+
+    /// Updates `weight_sum`/`weight_sq_sum` and the weighted-Welford
+    /// accumulators (`mean_acc`/`s_stat`) for a single bin, generalizing
+    /// Welford's online algorithm to weighted samples (West, 1979): an
+    /// unweighted sample is the special case `weight == 1`, for which this
+    /// reduces exactly to the plain running mean/`M2` update.
+    fn accumulate_weighted(&mut self, bin_index: &[usize], value: T, weight: T)
+    where
+        T: Copy + num_traits::Num,
+    {
+        self.weight_sum[bin_index] = self.weight_sum[bin_index] + weight;
+        self.weight_sq_sum[bin_index] = self.weight_sq_sum[bin_index] + weight * weight;
+
+        let weight_sum = self.weight_sum[bin_index];
+        if weight_sum != T::zero() {
+            let delta = value - self.mean_acc[bin_index];
+            self.mean_acc[bin_index] = self.mean_acc[bin_index] + (weight / weight_sum) * delta;
+            let delta2 = value - self.mean_acc[bin_index];
+            self.s_stat[bin_index] = self.s_stat[bin_index] + weight * delta * delta2;
+        }
+    }
+
     /// Returns the number of dimensions of the space the binned statistic is covering.
     pub fn ndim(&self) -> usize {
         debug_assert_eq!(self.counts.ndim(), self.grid.ndim());
@@ -88,22 +306,408 @@ where
     pub fn grid(&self) -> &Grid<A> {
         &self.grid
     }
+
+    /// Borrows a view on the per-bin sum of weights (`Σw`); equal to
+    /// [`counts`] unless some samples were added via [`add_weighted_sample`].
+    ///
+    /// [`counts`]: #method.counts
+    /// [`add_weighted_sample`]: #method.add_weighted_sample
+    pub fn weight_sum(&self) -> ArrayViewD<'_, T> {
+        self.weight_sum.view()
+    }
+
+    /// Borrows a view on the per-bin sum of squared weights (`Σw²`); equal to
+    /// [`counts`] unless some samples were added via [`add_weighted_sample`].
+    ///
+    /// [`counts`]: #method.counts
+    /// [`add_weighted_sample`]: #method.add_weighted_sample
+    pub fn weight_sq_sum(&self) -> ArrayViewD<'_, T> {
+        self.weight_sq_sum.view()
+    }
+
+    /// Returns the [`Statistic`] that [`result`] reduces each bin to.
+    ///
+    /// [`Statistic`]: enum.Statistic.html
+    /// [`result`]: #method.result
+    pub fn statistic(&self) -> Statistic {
+        self.statistic
+    }
+
+    /// Returns the multinomial variance of a single bin's `count`, `k * (1 - k / N)`,
+    /// where `N` is the total number of samples added so far.
+    ///
+    /// Its square root estimates the statistical error of a plain histogram bin
+    /// count, as opposed to [`variance`]/[`std`] which describe the spread of the
+    /// *values* falling into a bin.
+    ///
+    /// [`variance`]: #method.variance
+    /// [`std`]: #method.std
+    pub fn count_variance(&self, bin: &[usize]) -> f64 {
+        let k = self.counts[bin] as f64;
+        let n: f64 = self.counts.iter().sum::<usize>() as f64;
+        if n == 0. {
+            0.
+        } else {
+            k * (1. - k / n)
+        }
+    }
 }
 
-impl<A: Ord, T: Copy + num_traits::Num + Add<Output = T>> Add for BinnedStatistic<A, T> {
-    type Output = Self;
+impl<A, T> BinnedStatistic<A, T>
+where
+    A: Ord,
+    T: Clone + Float,
+{
+    /// Reduces the values that fell into each bin according to the
+    /// [`Statistic`] this `BinnedStatistic` was built with (see
+    /// [`with_statistic`]), matching SciPy's `binned_statistic_dd`.
+    ///
+    /// **Deliberate deviation from SciPy:** SciPy's `binned_statistic_dd`
+    /// reports `NaN` for an empty bin, so "no data" and "data happens to
+    /// reduce to zero" stay distinguishable downstream. This `result` reports
+    /// `0` instead for every statistic (including `Min`/`Max`/`Mean`/
+    /// `Median`), which is indistinguishable from a real `0` value — callers
+    /// porting SciPy code that relies on `NaN`-propagation (e.g. summing
+    /// `Min` across bins and expecting an empty bin to poison the sum) will
+    /// get a silently different answer. Use [`counts`] to detect empty bins
+    /// explicitly if that distinction matters.
+    ///
+    /// `Std` is the sample standard deviation of [`std`] (denominator
+    /// `n - 1`), whereas SciPy's `std` divides by `n`.
+    ///
+    /// [`std`]: #method.std
+    /// [`Statistic`]: enum.Statistic.html
+    /// [`with_statistic`]: #method.with_statistic
+    /// [`counts`]: #method.counts
+    pub fn result(&self) -> ArrayD<T> {
+        match self.statistic {
+            Statistic::Count => self.counts.mapv(|n| T::from(n).unwrap()),
+            Statistic::Sum => self.sum.clone(),
+            Statistic::Mean => self.mean(),
+            Statistic::Std => self.std(),
+            Statistic::Min => self.min.mapv(|m| m.unwrap_or_else(T::zero)),
+            Statistic::Max => self.max.mapv(|m| m.unwrap_or_else(T::zero)),
+            Statistic::Median => self.median(),
+        }
+    }
+
+    fn mean(&self) -> ArrayD<T> {
+        // Dividing by `weight_sum` (rather than `counts`) gives the correct
+        // importance-weighted mean for bins fed by `add_weighted_sample`,
+        // while behaving exactly like `sum / counts` for plain `add_sample`
+        // data, since an unweighted sample contributes a weight of 1.
+        Zip::from(&self.sum)
+            .and(&self.weight_sum)
+            .map_collect(|&sum, &w| {
+                if w == T::zero() {
+                    T::zero()
+                } else {
+                    sum / w
+                }
+            })
+    }
 
-    fn add(self, other: Self) -> Self {
-        if self.grid != other.grid {
-            panic!("`BinnedStatistics` can only be added for the same `grid`!")
-        };
+    /// Returns the per-bin sample variance of the values falling in each bin,
+    /// computed online via a weighted generalization of Welford's algorithm
+    /// (no second pass over the data, no retained samples) — see
+    /// [`add_weighted_sample`] for the accumulator this draws on. The
+    /// unbiased reliability-weights denominator `Σw - Σw²/Σw` reduces to the
+    /// familiar `n - 1` when every sample carries weight `1`. Untouched bins
+    /// and bins with a non-positive denominator (an effective sample count
+    /// `(Σw)² / Σw²` of at most 1, e.g. a single sample) report `0`.
+    ///
+    /// [`add_weighted_sample`]: #method.add_weighted_sample
+    pub fn variance(&self) -> ArrayD<T> {
+        Zip::from(&self.s_stat)
+            .and(&self.weight_sum)
+            .and(&self.weight_sq_sum)
+            .map_collect(|&s, &w, &w2| {
+                if w == T::zero() {
+                    T::zero()
+                } else {
+                    let denom = w - w2 / w;
+                    if denom <= T::zero() {
+                        T::zero()
+                    } else {
+                        s / denom
+                    }
+                }
+            })
+    }
 
-        BinnedStatistic {
-            counts: &self.counts + &other.counts,
-            sum: &self.sum + &other.sum,
-            grid: self.grid,
+    /// Returns the per-bin sample standard deviation, `sqrt(variance)` —
+    /// consistent with [`Statistic::Std`]'s reduction in [`result`]. Bins
+    /// with fewer than 2 samples report `0`.
+    ///
+    /// [`Statistic::Std`]: enum.Statistic.html#variant.Std
+    /// [`result`]: #method.result
+    pub fn std(&self) -> ArrayD<T> {
+        self.variance().mapv(Float::sqrt)
+    }
+
+    /// Returns the standard error of the per-bin mean, `sqrt(variance /
+    /// effective_count)` — a per-bin error bar suitable for physics-style
+    /// histograms, distinct from [`std`] which describes the spread of the
+    /// values themselves. Divides by [`effective_count`] rather than the raw
+    /// sample count, so weighted data is propagated correctly; the two agree
+    /// whenever every sample carries weight `1`. Bins with fewer than 2
+    /// effective samples report `0`.
+    ///
+    /// [`std`]: #method.std
+    /// [`effective_count`]: #method.effective_count
+    pub fn standard_error(&self) -> ArrayD<T> {
+        let variance = self.variance();
+        ArrayD::from_shape_fn(variance.shape(), |index| {
+            let bin = index.slice();
+            let effective_count = self.effective_count(bin);
+            if effective_count < T::one() + T::one() {
+                T::zero()
+            } else {
+                (variance[bin] / effective_count).sqrt()
+            }
+        })
+    }
+
+    /// Returns the effective sample size of a single bin under importance
+    /// weighting, `(Σw)² / Σw²`, derived from [`weight_sum`]/[`weight_sq_sum`].
+    /// Equal to the plain sample count unless some samples were added via
+    /// [`add_weighted_sample`]. Reports `0` for an empty bin.
+    ///
+    /// [`weight_sum`]: #method.weight_sum
+    /// [`weight_sq_sum`]: #method.weight_sq_sum
+    /// [`add_weighted_sample`]: #method.add_weighted_sample
+    pub fn effective_count(&self, bin: &[usize]) -> T {
+        let w = self.weight_sum[bin];
+        let w2 = self.weight_sq_sum[bin];
+        if w2 == T::zero() {
+            T::zero()
+        } else {
+            w * w / w2
         }
     }
+
+    fn median(&self) -> ArrayD<T> {
+        self.values.mapv(|values| {
+            if values.is_empty() {
+                return T::zero();
+            }
+            let mut values = values.clone();
+            // `partial_cmp` returns `None` only for NaN; treat those as equal
+            // rather than panicking, so a single NaN sample can't crash an
+            // otherwise well-formed reduction.
+            values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+            let mid = values.len() / 2;
+            if values.len() % 2 == 0 {
+                (values[mid - 1] + values[mid]) / (T::one() + T::one())
+            } else {
+                values[mid]
+            }
+        })
+    }
+}
+
+impl<A, T> BinnedStatistic<A, T>
+where
+    A: Ord,
+    T: Copy + Float,
+{
+    /// Merges `other` into `self` in place, element-wise combining `counts`,
+    /// `sum` and every statistic accumulator, without consuming either operand.
+    ///
+    /// Returns [`MergeError::GridMismatch`] rather than panicking when
+    /// `self.grid() != other.grid()`, so many partial `BinnedStatistic`s (e.g.
+    /// one per thread) can be folded into one without risking an unwind.
+    /// Returns [`MergeError::StatisticMismatch`] if `self` and `other` were
+    /// not built with the same [`Statistic`], since only the `min`/`max`/
+    /// `values` tracked for that statistic are meaningful to combine.
+    ///
+    /// [`MergeError::GridMismatch`]: enum.MergeError.html#variant.GridMismatch
+    /// [`MergeError::StatisticMismatch`]: enum.MergeError.html#variant.StatisticMismatch
+    /// [`Statistic`]: enum.Statistic.html
+    pub fn merge(&mut self, other: &Self) -> Result<(), MergeError> {
+        if self.grid != other.grid {
+            return Err(MergeError::GridMismatch);
+        }
+        if self.statistic != other.statistic {
+            return Err(MergeError::StatisticMismatch);
+        }
+
+        // Chan et al.'s parallel merge of two (weighted) Welford accumulators:
+        // combine the running means weighted by `Σw`, and correct the
+        // combined sum-of-squared-deviations for the gap between the two
+        // sub-means. Using `weight_sum` instead of `counts` as the weight
+        // generalizes this to bins touched by `add_weighted_sample`.
+        let mean_acc = Zip::from(&self.mean_acc)
+            .and(&other.mean_acc)
+            .and(&self.weight_sum)
+            .and(&other.weight_sum)
+            .map_collect(|&ma, &mb, &wa, &wb| {
+                if wa + wb == T::zero() {
+                    T::zero()
+                } else {
+                    (ma * wa + mb * wb) / (wa + wb)
+                }
+            });
+        let s_stat = Zip::from(&self.s_stat)
+            .and(&other.s_stat)
+            .and(&self.mean_acc)
+            .and(&other.mean_acc)
+            .and(&self.weight_sum)
+            .and(&other.weight_sum)
+            .map_collect(|&sa, &sb, &ma, &mb, &wa, &wb| {
+                if wa == T::zero() {
+                    sb
+                } else if wb == T::zero() {
+                    sa
+                } else {
+                    let delta = mb - ma;
+                    sa + sb + delta * delta * wa * wb / (wa + wb)
+                }
+            });
+        let min = Zip::from(&self.min)
+            .and(&other.min)
+            .map_collect(|&a, &b| match (a, b) {
+                (Some(a), Some(b)) => Some(if a < b { a } else { b }),
+                (a, None) => a,
+                (None, b) => b,
+            });
+        let max = Zip::from(&self.max)
+            .and(&other.max)
+            .map_collect(|&a, &b| match (a, b) {
+                (Some(a), Some(b)) => Some(if a > b { a } else { b }),
+                (a, None) => a,
+                (None, b) => b,
+            });
+        let values = Zip::from(&self.values)
+            .and(&other.values)
+            .map_collect(|a, b| a.iter().chain(b).copied().collect());
+
+        self.counts = &self.counts + &other.counts;
+        self.sum = &self.sum + &other.sum;
+        self.mean_acc = mean_acc;
+        self.s_stat = s_stat;
+        self.min = min;
+        self.max = max;
+        self.values = values;
+        self.weight_sum = &self.weight_sum + &other.weight_sum;
+        self.weight_sq_sum = &self.weight_sq_sum + &other.weight_sq_sum;
+        Ok(())
+    }
+}
+
+impl<'a, A, T> Add for &'a BinnedStatistic<A, T>
+where
+    A: Ord + Clone,
+    T: Copy + Float,
+{
+    type Output = Result<BinnedStatistic<A, T>, MergeError>;
+
+    /// Checked merge of two `BinnedStatistic`s, mirroring [`merge`] but
+    /// without mutating either operand. Returns [`MergeError`] instead of
+    /// panicking when the two grids or statistics differ, so an iterator of
+    /// partial `BinnedStatistic`s (e.g. one per thread) can be folded with
+    /// `+` without risking an unwind.
+    ///
+    /// Note: this supersedes the previous by-value `impl Add for
+    /// BinnedStatistic<A, T>`, which panicked on a grid mismatch instead of
+    /// returning a `Result` — that panicking impl has been removed rather
+    /// than kept alongside this one, since the two would otherwise silently
+    /// disagree on merge semantics for the same operator. This is a breaking
+    /// change for any downstream caller still relying on by-value `a + b`.
+    ///
+    /// [`merge`]: struct.BinnedStatistic.html#method.merge
+    /// [`MergeError`]: enum.MergeError.html
+    fn add(self, other: Self) -> Self::Output {
+        let mut merged = BinnedStatistic::with_statistic(self.grid.clone(), self.statistic);
+        merged.merge(self)?;
+        merged.merge(other)?;
+        Ok(merged)
+    }
+}
+
+impl<A, T> BinnedStatistic<A, T>
+where
+    A: Ord + Clone + ToPrimitive,
+    T: Clone + num_traits::Num + ToPrimitive,
+{
+    /// Returns the volume of each bin — the product of its per-axis widths,
+    /// as derived from the [`Edges`] of `self.grid`'s [`Bins`] — so that
+    /// callers can normalize their own derived statistics the same way
+    /// [`density`] does.
+    ///
+    /// Bins with a zero-width edge along any axis report a volume of `0.`
+    /// rather than propagating the degeneracy into `NaN` downstream.
+    ///
+    /// [`Edges`]: struct.Edges.html
+    /// [`Bins`]: struct.Bins.html
+    /// [`density`]: #method.density
+    pub fn bin_volumes(&self) -> ArrayD<f64> {
+        let widths: Vec<Vec<f64>> = self
+            .grid
+            .projections()
+            .iter()
+            .map(|bins| {
+                let edges = bins.edges();
+                (0..bins.len())
+                    .map(|i| {
+                        let left = edges[i].to_f64().unwrap();
+                        let right = edges[i + 1].to_f64().unwrap();
+                        right - left
+                    })
+                    .collect()
+            })
+            .collect();
+
+        ArrayD::from_shape_fn(self.grid.shape(), |index| {
+            index
+                .slice()
+                .iter()
+                .zip(&widths)
+                .map(|(&i, axis_widths)| axis_widths[i])
+                .product()
+        })
+    }
+
+    /// Returns `counts` normalized by [`bin_volumes`] and the grand total
+    /// count, i.e. the bin counts expressed as a probability density that
+    /// integrates to `1` over the grid. Useful for comparing histograms with
+    /// unequal bin widths or differing dimensionality.
+    ///
+    /// Mirrors the conventional meaning of `density` as used by e.g. NumPy's
+    /// and SciPy's `density=True` — a normalized *count*, not a normalized
+    /// sum. See [`normalized_sum`] for the latter.
+    ///
+    /// [`bin_volumes`]: #method.bin_volumes
+    /// [`normalized_sum`]: #method.normalized_sum
+    pub fn density(&self) -> ArrayD<f64> {
+        let total = self.counts.iter().sum::<usize>() as f64;
+        self.density_of(&self.counts.mapv(|n| n as f64), total)
+    }
+
+    /// Returns `sum` normalized by [`bin_volumes`] and the grand total count,
+    /// so that the result integrates to `sum`'s density over the grid. Unlike
+    /// [`density`], which normalizes the bin *counts*, this normalizes the
+    /// per-bin sum of values.
+    ///
+    /// [`bin_volumes`]: #method.bin_volumes
+    /// [`density`]: #method.density
+    pub fn normalized_sum(&self) -> ArrayD<f64> {
+        let total = self.counts.iter().sum::<usize>() as f64;
+        self.density_of(&self.sum.mapv(|v| v.to_f64().unwrap()), total)
+    }
+
+    fn density_of(&self, values: &ArrayD<f64>, total: f64) -> ArrayD<f64> {
+        let volumes = self.bin_volumes();
+        Zip::from(values)
+            .and(&volumes)
+            .map_collect(|&value, &volume| {
+                if total == 0. || volume == 0. {
+                    0.
+                } else {
+                    value / (volume * total)
+                }
+            })
+    }
 }
 
 /// Extension trait for `ArrayBase` providing methods to compute histograms.
@@ -170,6 +774,47 @@ where
     where
         A: Ord;
 
+    /// Like [`binned_statistic`], but the resulting [`BinnedStatistic`] is
+    /// built with the given [`Statistic`], so that [`BinnedStatistic::result`]
+    /// reduces each bin accordingly instead of only exposing `count`/`sum`.
+    ///
+    /// [`binned_statistic`]: #tymethod.binned_statistic
+    /// [`BinnedStatistic`]: struct.BinnedStatistic.html
+    /// [`Statistic`]: enum.Statistic.html
+    /// [`BinnedStatistic::result`]: struct.BinnedStatistic.html#method.result
+    fn binned_statistic_with(
+        &self,
+        grid: Grid<A>,
+        values: ArrayD<T>,
+        statistic: Statistic,
+    ) -> BinnedStatistic<A, T>
+    where
+        A: Ord;
+
+    /// Like [`binned_statistic`], but drives
+    /// [`BinnedStatistic::add_weighted_sample`] with a per-sample `weights`
+    /// array instead of an implicit weight of `1`, so that `sum` accumulates
+    /// `weight * value` and the resulting [`BinnedStatistic`] exposes
+    /// [`weight_sum`]/[`weight_sq_sum`] for error propagation (effective
+    /// count = `(Σw)² / Σw²`). This supports importance-sampled data where
+    /// each observation carries an acceptance weight, e.g. Monte-Carlo output.
+    ///
+    /// Important: points outside the grid are ignored, same as [`binned_statistic`]!
+    ///
+    /// [`binned_statistic`]: #tymethod.binned_statistic
+    /// [`BinnedStatistic`]: struct.BinnedStatistic.html
+    /// [`BinnedStatistic::add_weighted_sample`]: struct.BinnedStatistic.html#method.add_weighted_sample
+    /// [`weight_sum`]: struct.BinnedStatistic.html#method.weight_sum
+    /// [`weight_sq_sum`]: struct.BinnedStatistic.html#method.weight_sq_sum
+    fn binned_statistic_weighted(
+        &self,
+        grid: Grid<A>,
+        values: ArrayD<T>,
+        weights: ArrayD<T>,
+    ) -> BinnedStatistic<A, T>
+    where
+        A: Ord;
+
     private_decl! {}
 }
 
@@ -177,15 +822,287 @@ impl<A, S, T> BinnedStatisticExt<A, S, T> for ArrayBase<S, Ix2>
 where
     S: Data<Elem = A>,
     A: Ord,
-    T: Copy + num_traits::Num,
+    T: Copy + num_traits::Num + PartialOrd,
 {
     fn binned_statistic(&self, grid: Grid<A>, values: ArrayD<T>) -> BinnedStatistic<A, T> {
-        let mut binned_statistic = BinnedStatistic::new(grid);
+        self.binned_statistic_with(grid, values, Statistic::Sum)
+    }
+
+    fn binned_statistic_with(
+        &self,
+        grid: Grid<A>,
+        values: ArrayD<T>,
+        statistic: Statistic,
+    ) -> BinnedStatistic<A, T> {
+        let mut binned_statistic = BinnedStatistic::with_statistic(grid, statistic);
         for (sample, value) in self.axis_iter(Axis(0)).zip(&values) {
             let _ = binned_statistic.add_sample(&sample, *value);
         }
         binned_statistic
     }
 
+    fn binned_statistic_weighted(
+        &self,
+        grid: Grid<A>,
+        values: ArrayD<T>,
+        weights: ArrayD<T>,
+    ) -> BinnedStatistic<A, T> {
+        let mut binned_statistic = BinnedStatistic::new(grid);
+        for ((sample, value), weight) in self.axis_iter(Axis(0)).zip(&values).zip(&weights) {
+            let _ = binned_statistic.add_weighted_sample(&sample, *value, *weight);
+        }
+        binned_statistic
+    }
+
     private_impl! {}
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+    use noisy_float::types::{n64, N64};
+
+    fn single_bin_grid() -> Grid<N64> {
+        let edges = Edges::from(vec![n64(0.), n64(10.)]);
+        Grid::from(vec![Bins::new(edges)])
+    }
+
+    #[test]
+    fn std_matches_statistic_std_result_not_standard_error() {
+        let mut binned_statistic = BinnedStatistic::with_statistic(single_bin_grid(), Statistic::Std);
+        for value in &[2., 4., 4., 4., 5., 5., 7., 9.] {
+            binned_statistic
+                .add_sample(&array![n64(1.)], n64(*value))
+                .unwrap();
+        }
+
+        let std = binned_statistic.std();
+        let standard_error = binned_statistic.standard_error();
+
+        assert_eq!(binned_statistic.result(), std);
+        assert_ne!(std, standard_error);
+    }
+
+    #[test]
+    fn variance_and_std_are_zero_below_two_samples() {
+        let mut binned_statistic =
+            BinnedStatistic::with_statistic(single_bin_grid(), Statistic::Std);
+        assert_eq!(binned_statistic.variance()[[0]], n64(0.));
+        assert_eq!(binned_statistic.std()[[0]], n64(0.));
+
+        binned_statistic
+            .add_sample(&array![n64(1.)], n64(3.))
+            .unwrap();
+        assert_eq!(binned_statistic.variance()[[0]], n64(0.));
+        assert_eq!(binned_statistic.std()[[0]], n64(0.));
+    }
+
+    #[test]
+    fn merge_matches_sequential_accumulation() {
+        let values = [2., 4., 4., 4., 5., 5., 7., 9.];
+
+        let mut sequential =
+            BinnedStatistic::with_statistic(single_bin_grid(), Statistic::Std);
+        for value in &values {
+            sequential
+                .add_sample(&array![n64(1.)], n64(*value))
+                .unwrap();
+        }
+
+        let (left_values, right_values) = values.split_at(3);
+        let mut left = BinnedStatistic::with_statistic(single_bin_grid(), Statistic::Std);
+        for value in left_values {
+            left.add_sample(&array![n64(1.)], n64(*value)).unwrap();
+        }
+        let mut right = BinnedStatistic::with_statistic(single_bin_grid(), Statistic::Std);
+        for value in right_values {
+            right.add_sample(&array![n64(1.)], n64(*value)).unwrap();
+        }
+        left.merge(&right).unwrap();
+
+        assert_eq!(left.counts(), sequential.counts());
+        assert_eq!(left.sum(), sequential.sum());
+        assert_eq!(left.variance(), sequential.variance());
+        assert_eq!(left.std(), sequential.std());
+    }
+
+    #[test]
+    fn merge_rejects_mismatched_statistic() {
+        let mut min_acc = BinnedStatistic::with_statistic(single_bin_grid(), Statistic::Min);
+        min_acc.add_sample(&array![n64(1.)], n64(2.)).unwrap();
+        let mut median_acc =
+            BinnedStatistic::with_statistic(single_bin_grid(), Statistic::Median);
+        median_acc.add_sample(&array![n64(1.)], n64(3.)).unwrap();
+
+        assert_eq!(
+            min_acc.merge(&median_acc),
+            Err(MergeError::StatisticMismatch)
+        );
+    }
+
+    #[test]
+    fn merge_rejects_mismatched_grid() {
+        let mut a = BinnedStatistic::new(single_bin_grid());
+        a.add_sample(&array![n64(1.)], n64(2.)).unwrap();
+        let other_grid = Grid::from(vec![Bins::new(Edges::from(vec![n64(0.), n64(5.)]))]);
+        let mut b = BinnedStatistic::new(other_grid);
+        b.add_sample(&array![n64(1.)], n64(3.)).unwrap();
+
+        assert_eq!(a.merge(&b), Err(MergeError::GridMismatch));
+    }
+
+    #[test]
+    fn density_and_bin_volumes_are_zero_not_nan_on_degenerate_bin() {
+        let edges = Edges::from(vec![n64(0.), n64(0.), n64(10.)]);
+        let grid = Grid::from(vec![Bins::new(edges)]);
+        let mut binned_statistic = BinnedStatistic::new(grid);
+        binned_statistic
+            .add_sample(&array![n64(5.)], n64(2.))
+            .unwrap();
+
+        let volumes = binned_statistic.bin_volumes();
+        let density = binned_statistic.density();
+        let normalized_sum = binned_statistic.normalized_sum();
+
+        assert_eq!(volumes[[0]], 0.);
+        assert_eq!(density[[0]], 0.);
+        assert_eq!(normalized_sum[[0]], 0.);
+        assert!(!density[[0]].is_nan());
+        assert!(!normalized_sum[[0]].is_nan());
+    }
+
+    #[test]
+    fn density_normalizes_counts_not_sum() {
+        let mut binned_statistic = BinnedStatistic::new(single_bin_grid());
+        binned_statistic
+            .add_sample(&array![n64(1.)], n64(4.))
+            .unwrap();
+        binned_statistic
+            .add_sample(&array![n64(1.)], n64(6.))
+            .unwrap();
+
+        // A single bin spanning the whole grid integrates to 1 regardless of
+        // the values summed into it — only the count feeds `density`.
+        assert_eq!(binned_statistic.density()[[0]], 0.1);
+        assert_ne!(binned_statistic.density(), binned_statistic.normalized_sum());
+    }
+
+    #[test]
+    fn unit_weights_match_plain_binned_statistic() {
+        let observations = array![[n64(1.)], [n64(4.)], [n64(7.)], [n64(9.)]];
+        let values = array![n64(2.), n64(4.), n64(6.), n64(8.)].into_dyn();
+        let weights = array![n64(1.), n64(1.), n64(1.), n64(1.)].into_dyn();
+
+        let plain = observations.binned_statistic_with(
+            single_bin_grid(),
+            values.clone(),
+            Statistic::Mean,
+        );
+        let weighted = observations.binned_statistic_weighted(single_bin_grid(), values, weights);
+
+        assert_eq!(plain.counts(), weighted.counts());
+        assert_eq!(plain.sum(), weighted.sum());
+        assert_eq!(plain.mean(), weighted.mean());
+        assert_eq!(plain.variance(), weighted.variance());
+        assert_eq!(plain.std(), weighted.std());
+    }
+
+    #[test]
+    fn weighted_variance_is_not_silently_zero() {
+        // Two samples with equal weight 2 at the same point as, elsewhere,
+        // two unweighted samples: the weighted variance should match the
+        // unweighted one, not collapse to 0 just because it went through
+        // `add_weighted_sample`.
+        let mut plain = BinnedStatistic::with_statistic(single_bin_grid(), Statistic::Std);
+        plain.add_sample(&array![n64(1.)], n64(2.)).unwrap();
+        plain.add_sample(&array![n64(1.)], n64(8.)).unwrap();
+
+        let mut weighted = BinnedStatistic::with_statistic(single_bin_grid(), Statistic::Std);
+        weighted
+            .add_weighted_sample(&array![n64(1.)], n64(2.), n64(2.))
+            .unwrap();
+        weighted
+            .add_weighted_sample(&array![n64(1.)], n64(8.), n64(2.))
+            .unwrap();
+
+        assert_ne!(weighted.variance()[[0]], n64(0.));
+        assert_eq!(weighted.variance(), plain.variance());
+        assert_eq!(weighted.std(), plain.std());
+        assert_eq!(weighted.result(), weighted.std());
+    }
+
+    #[test]
+    fn result_reduces_per_statistic() {
+        let values = [2., 4., 4., 9., 5.];
+
+        let mut mean_acc = BinnedStatistic::with_statistic(single_bin_grid(), Statistic::Mean);
+        let mut min_acc = BinnedStatistic::with_statistic(single_bin_grid(), Statistic::Min);
+        let mut max_acc = BinnedStatistic::with_statistic(single_bin_grid(), Statistic::Max);
+        let mut median_acc =
+            BinnedStatistic::with_statistic(single_bin_grid(), Statistic::Median);
+        for value in &values {
+            mean_acc.add_sample(&array![n64(1.)], n64(*value)).unwrap();
+            min_acc.add_sample(&array![n64(1.)], n64(*value)).unwrap();
+            max_acc.add_sample(&array![n64(1.)], n64(*value)).unwrap();
+            median_acc
+                .add_sample(&array![n64(1.)], n64(*value))
+                .unwrap();
+        }
+
+        assert_eq!(mean_acc.result()[[0]], n64(4.8));
+        assert_eq!(min_acc.result()[[0]], n64(2.));
+        assert_eq!(max_acc.result()[[0]], n64(9.));
+        assert_eq!(median_acc.result()[[0]], n64(4.));
+    }
+
+    #[test]
+    fn weighted_sample_tracks_min_max_median_like_add_sample() {
+        let values = [2., 4., 4., 9., 5.];
+
+        let mut min_acc = BinnedStatistic::with_statistic(single_bin_grid(), Statistic::Min);
+        let mut max_acc = BinnedStatistic::with_statistic(single_bin_grid(), Statistic::Max);
+        let mut median_acc =
+            BinnedStatistic::with_statistic(single_bin_grid(), Statistic::Median);
+        for value in &values {
+            min_acc
+                .add_weighted_sample(&array![n64(1.)], n64(*value), n64(2.))
+                .unwrap();
+            max_acc
+                .add_weighted_sample(&array![n64(1.)], n64(*value), n64(2.))
+                .unwrap();
+            median_acc
+                .add_weighted_sample(&array![n64(1.)], n64(*value), n64(2.))
+                .unwrap();
+        }
+
+        assert_eq!(min_acc.result()[[0]], n64(2.));
+        assert_eq!(max_acc.result()[[0]], n64(9.));
+        assert_eq!(median_acc.result()[[0]], n64(4.));
+    }
+
+    #[test]
+    fn median_does_not_panic_on_nan() {
+        let mut median_acc: BinnedStatistic<N64, f64> =
+            BinnedStatistic::with_statistic(single_bin_grid(), Statistic::Median);
+        median_acc.add_sample(&array![n64(1.)], 1.).unwrap();
+        median_acc.add_sample(&array![n64(1.)], f64::NAN).unwrap();
+        median_acc.add_sample(&array![n64(1.)], 3.).unwrap();
+
+        // Just must not panic; NaN's position among the sorted values is
+        // unspecified, so there's no single correct median to assert on.
+        let _ = median_acc.result();
+    }
+
+    #[test]
+    fn empty_bin_reports_zero_not_nan_distinguishable_via_counts() {
+        let min_acc: BinnedStatistic<N64, N64> =
+            BinnedStatistic::with_statistic(single_bin_grid(), Statistic::Min);
+
+        // Deliberate deviation from SciPy (which reports NaN): an empty bin
+        // here is indistinguishable from a real value of 0 in `result()`
+        // alone, so callers who need to tell them apart must check `counts`.
+        assert_eq!(min_acc.counts()[[0]], 0);
+        assert_eq!(min_acc.result()[[0]], n64(0.));
+    }
+}